@@ -1,8 +1,15 @@
 use crate::common::*;
 
-fn parse_mrt(
+/// AS_PATH segment type codes, as defined by RFC 4271 (`AS_SET`/`AS_SEQUENCE`) and
+/// RFC 5065 (`AS_CONFED_SEQUENCE`/`AS_CONFED_SET`).
+const AS_SET: u8 = 1;
+const AS_SEQUENCE: u8 = 2;
+const AS_CONFED_SEQUENCE: u8 = 3;
+const AS_CONFED_SET: u8 = 4;
+
+pub(crate) fn parse_mrt(
     reader: &mut dyn Read,
-    mrt_hm: &mut HashMap<Address, HashSet<Vec<u32>>>,
+    mrt_hm: &mut HashMap<Address, HashSet<AsPath>>,
 ) -> Result<()> {
     let mut reader = Reader { stream: reader };
 
@@ -20,25 +27,10 @@ fn parse_mrt(
                     }
                 }
                 TABLE_DUMP_V2::RIB_IPV4_UNICAST(entry) => {
-                    for rib_entry in entry.entries {
-                        let index = rib_entry.peer_index as usize;
-                        addresses[index].mask = Some(entry.prefix_length);
-
-                        match as_path_from_bgp_attributes(rib_entry.attributes) {
-                            Ok(mut as_path) => {
-                                as_path.dedup();
-
-                                mrt_hm
-                                    .entry(addresses[index])
-                                    .or_insert_with(HashSet::new)
-                                    .insert(as_path);
-                            }
-                            Err(e) => {
-                                println!("ERROR: {:?}", e);
-                                continue;
-                            }
-                        };
-                    }
+                    insert_rib_entries(entry.prefix_length, entry.entries, &mut addresses, mrt_hm);
+                }
+                TABLE_DUMP_V2::RIB_IPV6_UNICAST(entry) => {
+                    insert_rib_entries(entry.prefix_length, entry.entries, &mut addresses, mrt_hm);
                 }
                 _ => continue,
             },
@@ -48,9 +40,40 @@ fn parse_mrt(
     Ok(())
 }
 
+/// Resolves each RIB entry's peer to an `Address` (IPv4 or IPv6, depending on which family
+/// the peer was registered under in the `PEER_INDEX_TABLE`), extracts its AS path, and
+/// records it in `mrt_hm`. Shared between `RIB_IPV4_UNICAST` and `RIB_IPV6_UNICAST` since
+/// both carry the same `prefix_length` / `entries` shape and only differ in address family.
+fn insert_rib_entries(
+    prefix_length: u8,
+    rib_entries: Vec<RIBEntry>,
+    addresses: &mut [Address],
+    mrt_hm: &mut HashMap<Address, HashSet<AsPath>>,
+) {
+    for rib_entry in rib_entries {
+        let index = rib_entry.peer_index as usize;
+        addresses[index].mask = Some(prefix_length);
+
+        match as_path_from_bgp_attributes(&rib_entry.attributes) {
+            Ok(mut as_path) => {
+                as_path.sequence.dedup();
+
+                mrt_hm
+                    .entry(addresses[index])
+                    .or_insert_with(HashSet::new)
+                    .insert(as_path);
+            }
+            Err(e) => {
+                println!("ERROR: {:?}", e);
+                continue;
+            }
+        };
+    }
+}
+
 pub(crate) fn parse_mrt_from_gz_url(
     url: &Url,
-    mrt_hm: &mut HashMap<Address, HashSet<Vec<u32>>>,
+    mrt_hm: &mut HashMap<Address, HashSet<AsPath>>,
 ) -> Result<()> {
     let res = reqwest::blocking::get(&url.to_string()).map_err(|reqwest_error| Error::Reqwest {
         url: url.to_string(),
@@ -64,7 +87,7 @@ pub(crate) fn parse_mrt_from_gz_url(
 #[cfg(test)]
 pub(crate) fn parse_mrt_from_file(
     path: &str,
-    mrt_hm: &mut HashMap<Address, HashSet<Vec<u32>>>,
+    mrt_hm: &mut HashMap<Address, HashSet<AsPath>>,
 ) -> Result<()> {
     let mut buffer = BufReader::new(File::open(path).map_err(|io_error| Error::IoError {
         io_error,
@@ -74,8 +97,56 @@ pub(crate) fn parse_mrt_from_file(
     parse_mrt(&mut buffer, mrt_hm)
 }
 
-/// Extracts an as path given a vec of bgp attributes
-fn as_path_from_bgp_attributes(mut bgp_attributes: Vec<u8>) -> Result<Vec<u32>, Error> {
+/// Reads a single byte at `cursor` and advances it, or errors if `buf` is exhausted.
+fn take_u8(buf: &[u8], cursor: &mut usize) -> Result<u8, Error> {
+    let byte = *buf.get(*cursor).ok_or_else(|| Error::MissingPathAttribute {
+        missing_attribute: String::from("attribute data truncated"),
+    })?;
+    *cursor += 1;
+    Ok(byte)
+}
+
+/// Borrows the next `n` bytes at `cursor` without copying and advances it, or errors if
+/// fewer than `n` bytes remain in `buf`.
+fn take(buf: &[u8], cursor: &mut usize, n: usize) -> Result<&[u8], Error> {
+    let end = cursor
+        .checked_add(n)
+        .filter(|end| *end <= buf.len())
+        .ok_or_else(|| Error::MissingPathAttribute {
+            missing_attribute: String::from("attribute data truncated"),
+        })?;
+    let slice = &buf[*cursor..end];
+    *cursor = end;
+    Ok(slice)
+}
+
+/// A parsed AS_PATH attribute: the concatenated `AS_SEQUENCE` segments in order (with any
+/// `AS_CONFED_SEQUENCE`/`AS_CONFED_SET` segments stripped out), and whether the path's last
+/// segment was an `AS_SET` rather than an `AS_SEQUENCE`. When `ends_in_as_set` is `true`, the
+/// last ASN in `sequence` is an aggregation point rather than a single reliable origin AS.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct AsPath {
+    sequence: Vec<u32>,
+    ends_in_as_set: bool,
+}
+
+impl AsPath {
+    #[cfg(test)]
+    pub(crate) fn new(sequence: Vec<u32>, ends_in_as_set: bool) -> Self {
+        AsPath {
+            sequence,
+            ends_in_as_set,
+        }
+    }
+}
+
+/// Extracts an AS path from a BGP path attributes buffer.
+///
+/// Walks `bgp_attributes` with a single cursor, reading the flag/type/length and
+/// big-endian ASNs directly from sub-slices instead of copying into new `Vec`s. Every read
+/// is bounds-checked, so truncated or malformed attribute data returns an `Error` instead of
+/// panicking.
+fn as_path_from_bgp_attributes(bgp_attributes: &[u8]) -> Result<AsPath, Error> {
     let mut as_path: Vec<u32> = Vec::new();
 
     // Return error is no BGP path attributes are found
@@ -85,44 +156,60 @@ fn as_path_from_bgp_attributes(mut bgp_attributes: Vec<u8>) -> Result<Vec<u32>,
         });
     }
 
+    let mut cursor = 0;
     loop {
-        let flag = bgp_attributes.remove(0);
-        let type_code = bgp_attributes.remove(0);
+        let flag = take_u8(bgp_attributes, &mut cursor)?;
+        let type_code = take_u8(bgp_attributes, &mut cursor)?;
         let attribute_length = match flag & (1 << 4) {
-            0 => bgp_attributes.remove(0) as usize,
-            _ => {
-                let length_bytes = vec![bgp_attributes.remove(0), bgp_attributes.remove(0)];
-                helper::read_be_u16(&mut length_bytes.as_slice())? as usize
-            }
+            0 => take_u8(bgp_attributes, &mut cursor)? as usize,
+            _ => helper::read_be_u16(&mut take(bgp_attributes, &mut cursor, 2)?)? as usize,
         };
 
         // Match on type_code and consume bgp_attributes values until AS Path attribute is found or return error
         match type_code {
-            1 | 3..=16 => bgp_attributes = bgp_attributes.split_off(attribute_length),
+            1 | 3..=16 => {
+                take(bgp_attributes, &mut cursor, attribute_length)?;
+            }
             2 => {
-                let as_set_indicator = bgp_attributes.remove(0);
-
-                // Determine if asn's are listed as an unordered AS_SET (1) or an ordered AS_SEQUENCE (2)
-                // Only add asn's to as_path vector if they are listed in an ordered AS_SEQUENCE
-                match as_set_indicator {
-                    1 => continue,
-                    2 => {
-                        let num_asn = bgp_attributes.remove(0);
-
-                        for _ in 0..num_asn {
-                            let mut asn_bytes = bgp_attributes.clone();
-                            bgp_attributes = asn_bytes.split_off(4);
-                            as_path.push(helper::read_be_u32(&mut asn_bytes.as_slice())?);
+                let attribute = take(bgp_attributes, &mut cursor, attribute_length)?;
+                let mut attr_cursor = 0;
+                let mut ends_in_as_set = false;
+
+                // AS_PATH is itself a sequence of segments: [segment_type, segment_length,
+                // segment_length ASNs]. Concatenate AS_SEQUENCE segments in order, silently
+                // strip confederation segments (they must not appear in the inferred
+                // origin), and remember whether the path ended in an AS_SET.
+                while attr_cursor < attribute.len() {
+                    let segment_type = take_u8(attribute, &mut attr_cursor)?;
+                    let segment_length = take_u8(attribute, &mut attr_cursor)?;
+
+                    match segment_type {
+                        AS_SEQUENCE => {
+                            ends_in_as_set = false;
+                            for _ in 0..segment_length {
+                                let mut asn_bytes = take(attribute, &mut attr_cursor, 4)?;
+                                as_path.push(helper::read_be_u32(&mut asn_bytes)?);
+                            }
+                        }
+                        AS_SET => {
+                            ends_in_as_set = true;
+                            take(attribute, &mut attr_cursor, segment_length as usize * 4)?;
+                        }
+                        AS_CONFED_SEQUENCE | AS_CONFED_SET => {
+                            take(attribute, &mut attr_cursor, segment_length as usize * 4)?;
+                        }
+                        _ => {
+                            return Err(Error::UnknownAsValue {
+                                unknown_as_value: segment_type,
+                            })
                         }
-
-                        return Ok(as_path);
-                    }
-                    _ => {
-                        return Err(Error::UnknownAsValue {
-                            unknown_as_value: as_set_indicator,
-                        })
                     }
                 }
+
+                return Ok(AsPath {
+                    sequence: as_path,
+                    ends_in_as_set,
+                });
             }
 
             _ => {
@@ -133,7 +220,7 @@ fn as_path_from_bgp_attributes(mut bgp_attributes: Vec<u8>) -> Result<Vec<u32>,
         }
 
         // Return an error if all bgp_attributes are exhausted and no AS Path type code
-        if bgp_attributes.is_empty() {
+        if cursor >= bgp_attributes.len() {
             return Err(Error::MissingPathAttribute {
                 missing_attribute: String::from("AS Path"),
             });
@@ -142,7 +229,7 @@ fn as_path_from_bgp_attributes(mut bgp_attributes: Vec<u8>) -> Result<Vec<u32>,
 }
 
 pub(crate) fn find_as_bottleneck(
-    mrt_hm: &mut HashMap<Address, HashSet<Vec<u32>>>,
+    mrt_hm: &mut HashMap<Address, HashSet<AsPath>>,
 ) -> Result<HashMap<Address, u32>, Error> {
     let mut prefix_to_common_suffix: HashMap<Address, Vec<u32>> = HashMap::new();
 
@@ -160,12 +247,117 @@ pub(crate) fn find_as_bottleneck(
     Ok(as_bottleneck)
 }
 
+/// A node in the binary radix trie used for longest-prefix-match AS lookups.
+#[derive(Debug, Default)]
+struct TrieNode {
+    asn: Option<u32>,
+    children: [Option<Box<TrieNode>>; 2],
+}
+
+/// A binary trie over IP prefix bits, MSB first, answering "which AS owns address X?".
+/// IPv4 and IPv6 get separate roots, since a v4 and v6 default route (or any two prefixes
+/// whose leading bits happen to coincide) must never match each other's lookups.
+#[derive(Debug, Default)]
+pub(crate) struct Trie {
+    v4_root: TrieNode,
+    v6_root: TrieNode,
+}
+
+impl Trie {
+    pub(crate) fn new() -> Self {
+        Trie::default()
+    }
+
+    /// Builds a trie from a prefix→ASN map, such as the output of `find_as_bottleneck`.
+    pub(crate) fn from_as_bottleneck(as_bottleneck: &HashMap<Address, u32>) -> Self {
+        let mut trie = Trie::new();
+        for (address, asn) in as_bottleneck {
+            trie.insert(*address, *asn);
+        }
+        trie
+    }
+
+    fn root(&self, ip: IpAddr) -> &TrieNode {
+        match ip {
+            IpAddr::V4(_) => &self.v4_root,
+            IpAddr::V6(_) => &self.v6_root,
+        }
+    }
+
+    fn root_mut(&mut self, ip: IpAddr) -> &mut TrieNode {
+        match ip {
+            IpAddr::V4(_) => &mut self.v4_root,
+            IpAddr::V6(_) => &mut self.v6_root,
+        }
+    }
+
+    /// Inserts `asn` at the node reached by walking `address`'s prefix bits from the MSB,
+    /// creating nodes as needed. A mask of `0` (the default route) stores `asn` at the root.
+    pub(crate) fn insert(&mut self, address: Address, asn: u32) {
+        let bits = address_bits(address.ip);
+        let mask = address.mask.unwrap_or(0) as usize;
+
+        let mut node = self.root_mut(address.ip);
+        for bit in bits.into_iter().take(mask) {
+            node = node.children[bit as usize].get_or_insert_with(|| Box::new(TrieNode::default()));
+        }
+        node.asn = Some(asn);
+    }
+
+    /// Finds the ASN of the longest prefix covering `ip`: walks `ip`'s bits from the MSB,
+    /// remembering the ASN of the deepest visited node that carries one. Returns `None` if
+    /// no prefix covers `ip`.
+    pub(crate) fn lookup(&self, ip: IpAddr) -> Option<u32> {
+        let mut node = self.root(ip);
+        let mut longest_match = node.asn;
+
+        for bit in address_bits(ip) {
+            match &node.children[bit as usize] {
+                Some(child) => {
+                    node = child;
+                    if node.asn.is_some() {
+                        longest_match = node.asn;
+                    }
+                }
+                None => break,
+            }
+        }
+
+        longest_match
+    }
+}
+
+/// Returns `ip`'s bits, most significant first: 32 bits for IPv4, 128 for IPv6.
+fn address_bits(ip: IpAddr) -> Vec<u8> {
+    let octets: Vec<u8> = match ip {
+        IpAddr::V4(v4) => v4.octets().to_vec(),
+        IpAddr::V6(v6) => v6.octets().to_vec(),
+    };
+
+    octets
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1))
+        .collect()
+}
+
 fn find_common_suffix(
-    mrt_hm: &mut HashMap<Address, HashSet<Vec<u32>>>,
+    mrt_hm: &mut HashMap<Address, HashSet<AsPath>>,
     prefix_to_common_suffix: &mut HashMap<Address, Vec<u32>>,
 ) -> Result<(), Error> {
     for (prefix, as_paths) in mrt_hm.iter() {
-        let mut as_paths_sorted: Vec<&Vec<u32>> = as_paths.iter().collect();
+        // Prefer paths with a reliable (non-AS_SET-terminated) origin; only fall back to
+        // AS_SET-terminated ones if that's all we observed for this prefix.
+        let reliable: Vec<&Vec<u32>> = as_paths
+            .iter()
+            .filter(|as_path| !as_path.ends_in_as_set)
+            .map(|as_path| &as_path.sequence)
+            .collect();
+
+        let mut as_paths_sorted: Vec<&Vec<u32>> = if reliable.is_empty() {
+            as_paths.iter().map(|as_path| &as_path.sequence).collect()
+        } else {
+            reliable
+        };
 
         as_paths_sorted.sort_by(|a, b| a.len().cmp(&b.len())); // descending
 
@@ -200,28 +392,155 @@ fn find_common_suffix(
 mod tests {
     use super::*;
 
-    fn setup_mrt_hm() -> Result<HashMap<Address, HashSet<Vec<u32>>>, Error> {
-        let mut mrt_hm: HashMap<Address, HashSet<Vec<u32>>> = HashMap::new();
+    fn as_path(sequence: Vec<u32>) -> AsPath {
+        AsPath {
+            sequence,
+            ends_in_as_set: false,
+        }
+    }
+
+    /// Builds a single BGP path attribute: flag/type/length header followed by `value`.
+    /// Uses the extended-length (2-byte) form when `extended_length` is set.
+    fn build_attribute(flag: u8, type_code: u8, value: &[u8], extended_length: bool) -> Vec<u8> {
+        let flag = if extended_length { flag | (1 << 4) } else { flag };
+        let mut attribute = vec![flag, type_code];
+        if extended_length {
+            attribute.extend_from_slice(&(value.len() as u16).to_be_bytes());
+        } else {
+            attribute.push(value.len() as u8);
+        }
+        attribute.extend_from_slice(value);
+        attribute
+    }
+
+    /// Builds a single AS_PATH segment: `[segment_type, segment_length, asns...]`.
+    fn build_segment(segment_type: u8, asns: &[u32]) -> Vec<u8> {
+        let mut segment = vec![segment_type, asns.len() as u8];
+        for asn in asns {
+            segment.extend_from_slice(&asn.to_be_bytes());
+        }
+        segment
+    }
+
+    #[test]
+    fn as_path_parser_errors_instead_of_panicking_on_truncated_attribute() {
+        // flag=0, type_code=2 (AS_PATH), length=10, but only 3 bytes of value follow.
+        let bgp_attributes = vec![0, 2, 10, 1, 2, 3];
+
+        assert!(as_path_from_bgp_attributes(&bgp_attributes).is_err());
+    }
+
+    #[test]
+    fn as_path_parser_errors_instead_of_panicking_on_truncated_header() {
+        // A single byte can't even hold the flag/type_code pair.
+        let bgp_attributes = vec![0];
+
+        assert!(as_path_from_bgp_attributes(&bgp_attributes).is_err());
+    }
+
+    #[test]
+    fn as_path_parser_errors_on_empty_attributes() {
+        assert!(as_path_from_bgp_attributes(&[]).is_err());
+    }
+
+    #[test]
+    fn as_path_parser_skips_preceding_attributes_and_supports_extended_length() -> Result<(), Error> {
+        let origin = build_attribute(0, 1, &[0], false);
+        let as_path_value = build_segment(AS_SEQUENCE, &[64271, 62240]);
+        let as_path_attr = build_attribute(0, 2, &as_path_value, true);
+
+        let mut bgp_attributes = origin;
+        bgp_attributes.extend_from_slice(&as_path_attr);
+
+        let have = as_path_from_bgp_attributes(&bgp_attributes)?;
+        assert_eq!(have, as_path(vec![64271, 62240]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn as_path_parser_concatenates_consecutive_as_sequence_segments() -> Result<(), Error> {
+        let mut value = build_segment(AS_SEQUENCE, &[64271, 62240]);
+        value.extend_from_slice(&build_segment(AS_SEQUENCE, &[3356, 174]));
+        let bgp_attributes = build_attribute(0, 2, &value, false);
+
+        let have = as_path_from_bgp_attributes(&bgp_attributes)?;
+        assert_eq!(have, as_path(vec![64271, 62240, 3356, 174]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn as_path_parser_strips_confederation_segments_without_affecting_sequence() -> Result<(), Error> {
+        let mut value = build_segment(AS_CONFED_SEQUENCE, &[64512]);
+        value.extend_from_slice(&build_segment(AS_CONFED_SET, &[64513, 64514]));
+        value.extend_from_slice(&build_segment(AS_SEQUENCE, &[64271, 62240]));
+        let bgp_attributes = build_attribute(0, 2, &value, false);
+
+        let have = as_path_from_bgp_attributes(&bgp_attributes)?;
+        assert_eq!(have, as_path(vec![64271, 62240]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn as_path_parser_records_as_set_termination() -> Result<(), Error> {
+        let mut value = build_segment(AS_SEQUENCE, &[64271, 62240]);
+        value.extend_from_slice(&build_segment(AS_SET, &[3356, 174]));
+        let bgp_attributes = build_attribute(0, 2, &value, false);
+
+        let have = as_path_from_bgp_attributes(&bgp_attributes)?;
+        assert_eq!(
+            have,
+            AsPath {
+                sequence: vec![64271, 62240],
+                ends_in_as_set: true,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn as_path_parser_clears_as_set_termination_once_as_sequence_follows() -> Result<(), Error> {
+        let mut value = build_segment(AS_SET, &[64271, 62240]);
+        value.extend_from_slice(&build_segment(AS_SEQUENCE, &[3356, 174]));
+        let bgp_attributes = build_attribute(0, 2, &value, false);
+
+        let have = as_path_from_bgp_attributes(&bgp_attributes)?;
+        assert_eq!(
+            have,
+            AsPath {
+                sequence: vec![3356, 174],
+                ends_in_as_set: false,
+            }
+        );
+
+        Ok(())
+    }
+
+    fn setup_mrt_hm() -> Result<HashMap<Address, HashSet<AsPath>>, Error> {
+        let mut mrt_hm: HashMap<Address, HashSet<AsPath>> = HashMap::new();
 
         mrt_hm
             .entry(Address::from_str("195.66.225.77/0")?)
             .or_insert_with(HashSet::new)
-            .insert(vec![64271, 62240, 3356]);
+            .insert(as_path(vec![64271, 62240, 3356]));
 
         mrt_hm
             .entry(Address::from_str("195.66.225.77/0")?)
             .or_insert_with(HashSet::new)
-            .insert(vec![64271, 62240, 174]);
+            .insert(as_path(vec![64271, 62240, 174]));
 
         mrt_hm
             .entry(Address::from_str("5.57.81.186/24")?)
             .or_insert_with(HashSet::new)
-            .insert(vec![6894, 13335, 38803, 56203]);
+            .insert(as_path(vec![6894, 13335, 38803, 56203]));
 
         mrt_hm
             .entry(Address::from_str("5.57.81.186/24")?)
             .or_insert_with(HashSet::new)
-            .insert(vec![6894, 13335, 4826, 174]);
+            .insert(as_path(vec![6894, 13335, 4826, 174]));
 
         Ok(mrt_hm)
     }
@@ -255,10 +574,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn trie_longest_prefix_match_wins_over_shorter_covering_prefix() -> Result<(), Error> {
+        let mut trie = Trie::new();
+        trie.insert(Address::from_str("192.0.0.0/8")?, 1);
+        trie.insert(Address::from_str("192.0.2.0/24")?, 2);
+
+        assert_eq!(trie.lookup("192.0.2.1".parse().unwrap()), Some(2));
+        assert_eq!(trie.lookup("192.1.2.3".parse().unwrap()), Some(1));
+        assert_eq!(trie.lookup("10.0.0.1".parse().unwrap()), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn trie_default_route_covers_everything_not_otherwise_matched_in_its_family() -> Result<(), Error> {
+        let mut trie = Trie::new();
+        trie.insert(Address::from_str("0.0.0.0/0")?, 1);
+        trie.insert(Address::from_str("2001:db8::/32")?, 2);
+
+        assert_eq!(trie.lookup("203.0.113.1".parse().unwrap()), Some(1));
+        assert_eq!(trie.lookup("2001:db8::1".parse().unwrap()), Some(2));
+        // No IPv6 default route was inserted, and the IPv4 default route must not leak
+        // across families.
+        assert_eq!(trie.lookup("2001:db9::1".parse().unwrap()), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn trie_keeps_ipv4_and_ipv6_default_routes_distinct() -> Result<(), Error> {
+        let mut trie = Trie::new();
+        trie.insert(Address::from_str("0.0.0.0/0")?, 1);
+        trie.insert(Address::from_str("::/0")?, 2);
+
+        assert_eq!(trie.lookup("203.0.113.1".parse().unwrap()), Some(1));
+        assert_eq!(trie.lookup("2001:db8::1".parse().unwrap()), Some(2));
+
+        Ok(())
+    }
+
     #[ignore]
     #[test]
     fn can_parse_mrt_from_file() -> Result<(), Error> {
-        let mut mrt_hm: HashMap<Address, HashSet<Vec<u32>>> = HashMap::new();
+        let mut mrt_hm: HashMap<Address, HashSet<AsPath>> = HashMap::new();
         let path = "data/latest-bview-2020-01-28-160000";
         assert_eq!(parse_mrt_from_file(path, &mut mrt_hm)?, ());
         assert_eq!(mrt_hm.is_empty(), false);
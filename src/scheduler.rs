@@ -0,0 +1,127 @@
+use crate::client::{Client, SyncClient};
+use crate::common::*;
+use std::thread;
+use std::time::Duration;
+
+/// Per-collector state tracked by the [`Scheduler`]: the collector's URL and the instant at
+/// which it next becomes eligible for a refresh.
+#[derive(Debug, Clone)]
+struct Collector {
+    url: Url,
+    next_fetch: SystemTime,
+}
+
+/// Periodically re-fetches a configurable set of MRT collectors (e.g. multiple RIPE RIS /
+/// RouteViews `latest-bview.gz` endpoints), merging each dump into a shared `mrt_hm`.
+/// Modeled on a seed-node scheduler: every collector carries a `next_fetch` timestamp,
+/// and collectors due at the same time are spread evenly across a `window` rather than
+/// fetched all at once.
+pub struct Scheduler {
+    collectors: Vec<Collector>,
+    window: Duration,
+    interval: Duration,
+}
+
+impl Scheduler {
+    /// Builds a scheduler over `urls`, seeding every collector with a `next_fetch` of
+    /// `now - 1 day` so they are all immediately eligible for refresh on boot.
+    pub fn new(urls: Vec<Url>, window: Duration, interval: Duration) -> Self {
+        let boot = SystemTime::now() - Duration::from_secs(24 * 60 * 60);
+
+        Scheduler {
+            collectors: urls
+                .into_iter()
+                .map(|url| Collector {
+                    url,
+                    next_fetch: boot,
+                })
+                .collect(),
+            window,
+            interval,
+        }
+    }
+
+    /// Indices of collectors whose `next_fetch` has passed as of `now`.
+    fn due_indices(&self, now: SystemTime) -> Vec<usize> {
+        self.collectors
+            .iter()
+            .enumerate()
+            .filter(|(_, collector)| collector.next_fetch <= now)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Runs the fetch loop forever, fetching each due collector via `client` and merging
+    /// the result into `mrt_hm`. Callers typically run this on its own thread.
+    pub fn run(&mut self, client: &dyn Client, mrt_hm: &mut HashMap<Address, HashSet<AsPath>>) -> Result<()> {
+        loop {
+            let due = self.due_indices(SystemTime::now());
+
+            if due.is_empty() {
+                thread::sleep(Duration::from_secs(1));
+                continue;
+            }
+
+            let delay = self.window / due.len() as u32;
+            for index in due {
+                let url = self.collectors[index].url.clone();
+                if let Err(e) = client.fetch_and_parse(&url, mrt_hm) {
+                    println!("ERROR: {:?}", e);
+                }
+                self.collectors[index].next_fetch = SystemTime::now() + self.interval;
+                thread::sleep(delay);
+            }
+        }
+    }
+}
+
+/// Entry point for callers who just want to run the scheduler loop over `urls`, re-fetching
+/// each collector every `interval` via a blocking [`SyncClient`].
+pub fn run_scheduler(
+    urls: Vec<Url>,
+    window: Duration,
+    interval: Duration,
+    mrt_hm: &mut HashMap<Address, HashSet<AsPath>>,
+) -> Result<()> {
+    Scheduler::new(urls, window, interval).run(&SyncClient, mrt_hm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scheduler_with(num_collectors: usize) -> Scheduler {
+        let urls = (0..num_collectors)
+            .map(|i| {
+                format!("http://collector{}.example/latest-bview.gz", i)
+                    .parse()
+                    .unwrap()
+            })
+            .collect();
+
+        Scheduler::new(urls, Duration::from_secs(60), Duration::from_secs(300))
+    }
+
+    #[test]
+    fn all_collectors_are_due_immediately_after_construction() {
+        let scheduler = scheduler_with(3);
+        assert_eq!(scheduler.due_indices(SystemTime::now()), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn only_collectors_past_their_next_fetch_are_due() {
+        let mut scheduler = scheduler_with(2);
+        scheduler.collectors[0].next_fetch = SystemTime::now() + Duration::from_secs(3600);
+
+        assert_eq!(scheduler.due_indices(SystemTime::now()), vec![1]);
+    }
+
+    #[test]
+    fn due_fetches_are_spread_evenly_across_the_window() {
+        let scheduler = scheduler_with(4);
+        let due = scheduler.due_indices(SystemTime::now());
+
+        let delay = scheduler.window / due.len() as u32;
+        assert_eq!(delay, Duration::from_secs(15));
+    }
+}